@@ -1,6 +1,6 @@
 // A simple application to track workouts that I've done.
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use bonsaidb::{
     core::{
         connection::{Connection, StorageConnection},
@@ -15,12 +15,183 @@ use bonsaidb::{
         Storage,
     },
 };
+use chrono::{Datelike, NaiveDate, NaiveTime};
 use clap::*;
 use prettytable::*;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 
 pub const DEFAULT_DB_PATH: &str = "./gymtracker";
 
+/// Expected textual format for a training session's date, i.e "03-14-2024".
+pub const DATE_FORMAT: &str = "%m-%d-%Y";
+/// Expected textual format for one end of a training session's time range, i.e "14:30".
+pub const TIME_FORMAT: &str = "%H:%M";
+
+/// A unit of body weight as entered on the command line.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum WeightUnit {
+    Lbs,
+    Kg,
+}
+
+impl WeightUnit {
+    fn to_lbs(self, value: f32) -> f32 {
+        match self {
+            WeightUnit::Lbs => value,
+            WeightUnit::Kg => value * 2.204_623,
+        }
+    }
+}
+
+impl fmt::Display for WeightUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightUnit::Lbs => write!(f, "lbs"),
+            WeightUnit::Kg => write!(f, "kg"),
+        }
+    }
+}
+
+/// A body weight measurement. `value`/`unit` preserve what the user entered;
+/// all comparisons and math should go through [`BodyWeight::lbs`], which is
+/// the canonical unit used internally.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct BodyWeight {
+    value: f32,
+    unit: WeightUnit,
+}
+
+impl BodyWeight {
+    /// Parse input like "185", "185lbs", or "84kg". Bare numbers are assumed to be lbs.
+    pub fn parse(raw: &str) -> Result<Self> {
+        let raw = raw.trim();
+        let (number, unit) = if let Some(number) = raw.strip_suffix("lbs") {
+            (number, WeightUnit::Lbs)
+        } else if let Some(number) = raw.strip_suffix("kg") {
+            (number, WeightUnit::Kg)
+        } else {
+            (raw, WeightUnit::Lbs)
+        };
+        let value: f32 = number
+            .trim()
+            .parse()
+            .with_context(|| format!("'{raw}' is not a valid body weight, i.e 185lbs or 84kg"))?;
+        if value <= 0.0 {
+            bail!("body weight must be a positive number, got '{raw}'");
+        }
+        Ok(BodyWeight { value, unit })
+    }
+
+    /// The weight converted to pounds, the canonical unit used for math/comparisons.
+    pub fn lbs(&self) -> f32 {
+        self.unit.to_lbs(self.value)
+    }
+}
+
+impl fmt::Display for BodyWeight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
+
+/// Parse a "00-00-0000" date into a [`NaiveDate`].
+fn parse_date(raw: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(raw, DATE_FORMAT)
+        .with_context(|| format!("'{raw}' is not a valid date, i.e 03-14-2024"))
+}
+
+/// Parse a "00:00-00:00" session time range into its start/end [`NaiveTime`]s.
+fn parse_time_range(raw: &str) -> Result<(NaiveTime, NaiveTime)> {
+    let (start, end) = raw
+        .split_once('-')
+        .with_context(|| format!("'{raw}' is not a valid time range, i.e 14:00-15:30"))?;
+    let start = NaiveTime::parse_from_str(start.trim(), TIME_FORMAT)
+        .with_context(|| format!("'{start}' is not a valid time, i.e 14:00"))?;
+    let end = NaiveTime::parse_from_str(end.trim(), TIME_FORMAT)
+        .with_context(|| format!("'{end}' is not a valid time, i.e 15:30"))?;
+    if end <= start {
+        bail!("session end time '{end}' must be after start time '{start}'");
+    }
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod parsing_tests {
+    use super::*;
+
+    #[test]
+    fn body_weight_bare_number_is_lbs() {
+        let weight = BodyWeight::parse("185").unwrap();
+        assert_eq!(weight.unit, WeightUnit::Lbs);
+        assert_eq!(weight.lbs(), 185.0);
+    }
+
+    #[test]
+    fn body_weight_lbs_suffix() {
+        let weight = BodyWeight::parse("185lbs").unwrap();
+        assert_eq!(weight.unit, WeightUnit::Lbs);
+        assert_eq!(weight.lbs(), 185.0);
+    }
+
+    #[test]
+    fn body_weight_kg_suffix_converts_to_lbs() {
+        let weight = BodyWeight::parse("84kg").unwrap();
+        assert_eq!(weight.unit, WeightUnit::Kg);
+        assert!((weight.lbs() - 185.188_33).abs() < 0.001);
+    }
+
+    #[test]
+    fn body_weight_rejects_negative() {
+        assert!(BodyWeight::parse("-10").is_err());
+    }
+
+    #[test]
+    fn body_weight_rejects_zero() {
+        assert!(BodyWeight::parse("0kg").is_err());
+    }
+
+    #[test]
+    fn body_weight_rejects_garbage() {
+        assert!(BodyWeight::parse("heavy").is_err());
+    }
+
+    #[test]
+    fn parse_date_accepts_expected_format() {
+        let date = parse_date("03-14-2024").unwrap();
+        assert_eq!(date, NaiveDate::from_ymd_opt(2024, 3, 14).unwrap());
+    }
+
+    #[test]
+    fn parse_date_rejects_bad_format() {
+        assert!(parse_date("2024-03-14").is_err());
+    }
+
+    #[test]
+    fn parse_time_range_accepts_expected_format() {
+        let (start, end) = parse_time_range("14:00-15:30").unwrap();
+        assert_eq!(start, NaiveTime::from_hms_opt(14, 0, 0).unwrap());
+        assert_eq!(end, NaiveTime::from_hms_opt(15, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn parse_time_range_rejects_end_before_start() {
+        assert!(parse_time_range("15:30-14:00").is_err());
+    }
+
+    #[test]
+    fn parse_time_range_rejects_end_equal_to_start() {
+        assert!(parse_time_range("14:00-14:00").is_err());
+    }
+
+    #[test]
+    fn parse_time_range_rejects_missing_separator() {
+        assert!(parse_time_range("14:00").is_err());
+    }
+}
+
 #[derive(Debug, Parser, PartialEq)]
 #[clap(
     name = "gymtracker",
@@ -28,6 +199,12 @@ pub const DEFAULT_DB_PATH: &str = "./gymtracker";
     about = "A simple application to track workout's"
 )]
 pub struct GymtrackerArgs {
+    /// Address of a running `gymtracker serve` instance to read/write through,
+    /// instead of local on-disk storage.
+    #[cfg(feature = "client")]
+    #[clap(long, global = true)]
+    pub remote: Option<String>,
+
     #[clap(subcommand)]
     pub user_method: MethodType,
 }
@@ -46,17 +223,50 @@ pub enum MethodType {
         date: String,
         /// Time of training session, i.e 00:00-00:00
         time: String,
-        /// Weight of user in lbs, i.e 000.0
-        body_weight: f32,
+        /// Weight of user, i.e 185lbs or 84kg
+        body_weight: String,
         /// Muscle's trained during session, i.e Back,\ Biceps
         muscle_group: String,
         /// Intensity of training session, range from 1-10
         intensity: u8,
     },
+    /// Search workout logs for a user by muscle group terms.
+    Search {
+        /// User's full name, i.e First\ Last
+        username: String,
+        /// Search terms, i.e "back bicep"
+        query: String,
+    },
+    /// Print aggregate training stats (sessions, time trained, average intensity/weight) for a user.
+    Summary { username: String },
+    /// Log a set/rep/load/etc. detail for an exercise within a tracked session.
+    AddExercise {
+        /// Id of the session (as printed by ReadLogs) the exercise belongs to.
+        session_id: u64,
+        /// Name of the exercise, i.e "Barbell Row"
+        exercise_name: String,
+        /// Attribute being recorded, i.e sets, reps, weight, rpe
+        attribute: String,
+        /// Value for the attribute; parsed as JSON if possible, otherwise kept as text.
+        value: String,
+    },
+    /// Print all exercise entries logged for a session.
+    ReadExercises {
+        /// Id of the session (as printed by ReadLogs) to list exercises for.
+        session_id: u64,
+    },
+    /// Rank a user's muscle groups by progressive-overload rating and flag ones trending down.
+    Progress { username: String },
+    /// Serve the workout database over the network for other devices to sync against.
+    #[cfg(feature = "server")]
+    Serve {
+        /// Address to listen on, i.e 0.0.0.0:5645
+        bind: String,
+    },
 }
 
 #[derive(Debug, Clone, Copy, View, ViewSchema, PartialEq)]
-#[view(collection = WorkoutInputs, key = String, value = (String, String, f32, String, u8), name = "by-user-name")]
+#[view(collection = WorkoutInputs, key = String, value = (NaiveDate, NaiveTime, NaiveTime, BodyWeight, String, u8), name = "by-user-name")]
 pub struct UserView;
 impl CollectionMapReduce for UserView {
     fn map<'doc>(
@@ -67,7 +277,8 @@ impl CollectionMapReduce for UserView {
             document.contents.username,
             (
                 document.contents.date,
-                document.contents.time,
+                document.contents.start_time,
+                document.contents.end_time,
                 document.contents.body_weight,
                 document.contents.muscle_group,
                 document.contents.intensity,
@@ -81,7 +292,7 @@ impl CollectionMapReduce for UserView {
         _rereduce: bool,
     ) -> ReduceResult<Self::View> {
         let mut username = &mappings[0].key;
-        let mut workout_info: &(String, String, f32, String, u8) = &mappings[0].value;
+        let mut workout_info = &mappings[0].value;
         for mapping in mappings.iter() {
             if &mapping.key == username {
                 username = &mapping.key;
@@ -92,8 +303,10 @@ impl CollectionMapReduce for UserView {
     }
 }
 
+/// Keyed on the date's ordinal day count (`NaiveDate::num_days_from_ce`) rather
+/// than the date itself, since that's what's comparable/range-queryable as a view key.
 #[derive(Debug, Clone, Copy, View, ViewSchema, PartialEq)]
-#[view(collection = WorkoutInputs, key = String, value = (String, String, f32, String, u8), name = "by-date")]
+#[view(collection = WorkoutInputs, key = i32, value = (String, NaiveTime, NaiveTime, BodyWeight, String, u8), name = "by-date")]
 pub struct DateView;
 impl CollectionMapReduce for DateView {
     fn map<'doc>(
@@ -101,10 +314,11 @@ impl CollectionMapReduce for DateView {
         document: CollectionDocument<WorkoutInputs>,
     ) -> ViewMapResult<'doc, Self::View> {
         document.header.emit_key_and_value(
-            document.contents.date,
+            document.contents.date.num_days_from_ce(),
             (
                 document.contents.username,
-                document.contents.time,
+                document.contents.start_time,
+                document.contents.end_time,
                 document.contents.body_weight,
                 document.contents.muscle_group,
                 document.contents.intensity,
@@ -118,7 +332,7 @@ impl CollectionMapReduce for DateView {
         _rereduce: bool,
     ) -> ReduceResult<Self::View> {
         let mut date = &mappings[0].key;
-        let mut workout_info: &(String, String, f32, String, u8) = &mappings[0].value;
+        let mut workout_info = &mappings[0].value;
         for mapping in mappings.iter() {
             if &mapping.key == date {
                 date = &mapping.key;
@@ -129,40 +343,342 @@ impl CollectionMapReduce for DateView {
     }
 }
 
+/// Lowercase `input` and split it into alphanumeric terms, dropping everything else.
+fn tokenize(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+        .collect()
+}
+
+/// Keyed on (username, term) rather than just the term, so a search for one user's
+/// sessions neither scores against nor pays the lookup cost of every other user's data.
+#[derive(Debug, Clone, Copy, View, ViewSchema, PartialEq)]
+#[view(collection = WorkoutInputs, key = (String, String), value = u32, name = "by-search-term")]
+pub struct SearchView;
+impl CollectionMapReduce for SearchView {
+    fn map<'doc>(
+        &self,
+        document: CollectionDocument<WorkoutInputs>,
+    ) -> ViewMapResult<'doc, Self::View> {
+        let mut term_frequencies: HashMap<String, u32> = HashMap::new();
+        for term in tokenize(&document.contents.muscle_group) {
+            *term_frequencies.entry(term).or_insert(0) += 1;
+        }
+
+        let username = document.contents.username.clone();
+        let mut mappings = Vec::new();
+        for (term, frequency) in term_frequencies {
+            mappings.extend(
+                document
+                    .header
+                    .clone()
+                    .emit_key_and_value((username.clone(), term), frequency)?,
+            );
+        }
+        Ok(mappings)
+    }
+
+    fn reduce(
+        &self,
+        mappings: &[ViewMappedValue<'_, Self>],
+        _rereduce: bool,
+    ) -> ReduceResult<Self::View> {
+        Ok(mappings.iter().map(|mapping| mapping.value).sum())
+    }
+}
+
+/// Aggregate training stats for a user, accumulated by [`StatsView`]'s reduce.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct WorkoutStats {
+    session_count: u64,
+    total_duration_minutes: u64,
+    intensity_sum: u64,
+    body_weight_sum: f32,
+}
+
+#[derive(Debug, Clone, Copy, View, ViewSchema, PartialEq)]
+#[view(collection = WorkoutInputs, key = String, value = WorkoutStats, name = "by-user-stats")]
+pub struct StatsView;
+impl CollectionMapReduce for StatsView {
+    fn map<'doc>(
+        &self,
+        document: CollectionDocument<WorkoutInputs>,
+    ) -> ViewMapResult<'doc, Self::View> {
+        document.header.emit_key_and_value(
+            document.contents.username.clone(),
+            WorkoutStats {
+                session_count: 1,
+                total_duration_minutes: document.contents.duration_minutes().max(0) as u64,
+                intensity_sum: document.contents.intensity as u64,
+                body_weight_sum: document.contents.body_weight.lbs(),
+            },
+        )
+    }
+
+    fn reduce(
+        &self,
+        mappings: &[ViewMappedValue<'_, Self>],
+        _rereduce: bool,
+    ) -> ReduceResult<Self::View> {
+        // Summation is associative, so folding mapped values works the same whether
+        // `mappings` holds raw per-document values or already-reduced partial totals.
+        Ok(mappings.iter().fold(WorkoutStats::default(), |mut totals, mapping| {
+            totals.session_count += mapping.value.session_count;
+            totals.total_duration_minutes += mapping.value.total_duration_minutes;
+            totals.intensity_sum += mapping.value.intensity_sum;
+            totals.body_weight_sum += mapping.value.body_weight_sum;
+            totals
+        }))
+    }
+}
+
+/// [`ExerciseEntry`] facts keyed by the id of the session they belong to,
+/// i.e session_id -> ("Barbell Row", "reps", 8).
+#[derive(Debug, Clone, Copy, View, ViewSchema, PartialEq)]
+#[view(collection = ExerciseEntry, key = u64, value = (String, String, JsonValue), name = "by-session")]
+pub struct ExerciseEntryView;
+impl CollectionMapReduce for ExerciseEntryView {
+    fn map<'doc>(
+        &self,
+        document: CollectionDocument<ExerciseEntry>,
+    ) -> ViewMapResult<'doc, Self::View> {
+        document.header.emit_key_and_value(
+            document.contents.session_id,
+            (
+                document.contents.exercise_name,
+                document.contents.attribute,
+                document.contents.value,
+            ),
+        )
+    }
+
+    // print_exercises_for_session (used by ReadLogs and ReadExercises) only ever calls
+    // `.query()` against this view, never `.query_reduce()`, so reduce has no caller. It
+    // passes through the last mapping.
+    fn reduce(
+        &self,
+        mappings: &[ViewMappedValue<'_, Self>],
+        _rereduce: bool,
+    ) -> ReduceResult<Self::View> {
+        Ok(mappings[mappings.len() - 1].value.clone())
+    }
+}
+
+#[derive(Collection, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[collection(name = "exercise-entries", views = [ExerciseEntryView])]
+pub struct ExerciseEntry {
+    session_id: u64,
+    exercise_name: String,
+    attribute: String,
+    value: JsonValue,
+}
+
+impl ExerciseEntry {
+    pub fn insert<C: Connection>(
+        connection: &C,
+        session_id: u64,
+        exercise_name: String,
+        attribute: String,
+        value: JsonValue,
+    ) -> Result<(), bonsaidb::core::Error> {
+        ExerciseEntry {
+            session_id,
+            exercise_name,
+            attribute,
+            value,
+        }
+        .push_into(connection)?;
+        Ok(())
+    }
+}
+
+/// An Elo-style baseline rating: a brand-new (username, muscle_group) pairing
+/// starts here and drifts up or down as sessions are logged.
+const INITIAL_RATING: f32 = 1000.0;
+/// Starting learning rate for the rating adjustment; decays as a pairing accumulates sessions.
+const BASE_K: f32 = 32.0;
+/// How many recent normalized-load samples to keep for trend detection.
+const RECENT_LOADS_WINDOW: usize = 10;
+
+/// Squash a session's `intensity * duration_minutes` workload into `[0, 1]`.
+fn normalized_load(intensity: u8, duration_minutes: i64) -> f32 {
+    let raw_load = intensity as f32 * duration_minutes.max(0) as f32;
+    raw_load / (raw_load + 450.0)
+}
+
+/// Logistic expectation derived from the current rating, mirroring a single-player Elo curve.
+fn expected_from_rating(rating: f32) -> f32 {
+    1.0 / (1.0 + (-(rating - INITIAL_RATING) / 400.0).exp())
+}
+
+/// Learning rate decays as a pairing accumulates sessions, so ratings stabilize over time.
+fn decayed_k(sessions_trained: u32) -> f32 {
+    BASE_K / (1.0 + sessions_trained as f32 / 10.0)
+}
+
+/// True if the second half of `recent_loads` averages lower than the first half,
+/// i.e. the user has been putting in less work recently.
+fn is_trending_down(recent_loads: &[f32]) -> bool {
+    if recent_loads.len() < 4 {
+        return false;
+    }
+    let midpoint = recent_loads.len() / 2;
+    let (earlier, later) = recent_loads.split_at(midpoint);
+    let average = |loads: &[f32]| loads.iter().sum::<f32>() / loads.len() as f32;
+    average(later) < average(earlier)
+}
+
+#[cfg(test)]
+mod rating_tests {
+    use super::*;
+
+    #[test]
+    fn normalized_load_is_zero_for_no_workload() {
+        assert_eq!(normalized_load(0, 0), 0.0);
+    }
+
+    #[test]
+    fn normalized_load_clamps_negative_duration_to_zero() {
+        assert_eq!(normalized_load(5, -30), 0.0);
+    }
+
+    #[test]
+    fn normalized_load_increases_with_workload_and_stays_under_one() {
+        let light = normalized_load(5, 30);
+        let heavy = normalized_load(10, 90);
+        assert!(light > 0.0 && light < 1.0);
+        assert!(heavy > light && heavy < 1.0);
+    }
+
+    #[test]
+    fn expected_from_rating_is_half_at_initial_rating() {
+        assert!((expected_from_rating(INITIAL_RATING) - 0.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn expected_from_rating_rises_above_initial_rating() {
+        assert!(expected_from_rating(INITIAL_RATING + 400.0) > 0.5);
+        assert!(expected_from_rating(INITIAL_RATING - 400.0) < 0.5);
+    }
+
+    #[test]
+    fn decayed_k_starts_at_base_k_and_decreases_with_sessions() {
+        assert_eq!(decayed_k(0), BASE_K);
+        assert!(decayed_k(10) < decayed_k(0));
+        assert!(decayed_k(100) < decayed_k(10));
+    }
+
+    #[test]
+    fn is_trending_down_false_with_too_few_samples() {
+        assert!(!is_trending_down(&[0.9, 0.8, 0.1]));
+    }
+
+    #[test]
+    fn is_trending_down_true_when_later_average_is_lower() {
+        assert!(is_trending_down(&[0.9, 0.9, 0.1, 0.1]));
+    }
+
+    #[test]
+    fn is_trending_down_false_when_later_average_is_higher() {
+        assert!(!is_trending_down(&[0.1, 0.1, 0.9, 0.9]));
+    }
+}
+
+/// A user's [`MuscleRating`]s keyed by username, one mapping per muscle group
+/// they have trained.
+#[derive(Debug, Clone, Copy, View, ViewSchema, PartialEq)]
+#[view(collection = MuscleRating, key = String, value = (String, f32, u32), name = "by-user-rating")]
+pub struct RatingView;
+impl CollectionMapReduce for RatingView {
+    fn map<'doc>(
+        &self,
+        document: CollectionDocument<MuscleRating>,
+    ) -> ViewMapResult<'doc, Self::View> {
+        document.header.emit_key_and_value(
+            document.contents.username.clone(),
+            (
+                document.contents.muscle_group.clone(),
+                document.contents.rating,
+                document.contents.sessions_trained,
+            ),
+        )
+    }
+
+    // print_progress only calls `.query()`/`.query_with_docs()` against this view, never
+    // `.query_reduce()`, so reduce has no caller. Left as an honest pass-through of the last
+    // mapping rather than the no-op "scan for a matching key" dead code it used to be copied
+    // in as.
+    fn reduce(
+        &self,
+        mappings: &[ViewMappedValue<'_, Self>],
+        _rereduce: bool,
+    ) -> ReduceResult<Self::View> {
+        Ok(mappings[mappings.len() - 1].value.clone())
+    }
+}
+
+/// Primary key of a [`MuscleRating`] document: deterministic per (username, muscle_group)
+/// pairing, so two concurrent writers for the same pairing (e.g. two devices logging a
+/// session near-simultaneously) contend over one document id instead of racing to each
+/// `push_into` their own duplicate.
+fn rating_key(username: &str, muscle_group: &str) -> String {
+    format!("{username}\0{muscle_group}")
+}
+
+#[derive(Collection, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[collection(name = "muscle-ratings", primary_key = String, views = [RatingView])]
+pub struct MuscleRating {
+    username: String,
+    muscle_group: String,
+    rating: f32,
+    sessions_trained: u32,
+    recent_loads: Vec<f32>,
+}
+
 struct WriteInputsForCLI {
     username: String,     //User's full name, i.e First\ Last
     date: String,         //Date of Training session, i.e 00-00-0000
     time: String,         //Time of Training session(Duration), i.e 00:00-00:00
-    body_weight: f32,     //Weight of user in lbs, i.e 000.0LBS ('merica)
+    body_weight: String,  //Weight of user, i.e 185lbs or 84kg
     muscle_group: String, //Muscle's trained during session, i.e Back, Bicep
     intensity: u8,        //Intensity of training session, range from 1-10
 }
 
 #[derive(Collection, Serialize, Deserialize, Clone, Debug, PartialEq)]
-#[collection(name= "workout-data", views = [UserView, DateView])]
+#[collection(name= "workout-data", views = [UserView, DateView, SearchView, StatsView])]
 pub struct WorkoutInputs {
     username: String,
-    date: String,
-    time: String,
-    body_weight: f32,
+    date: NaiveDate,
+    start_time: NaiveTime,
+    end_time: NaiveTime,
+    body_weight: BodyWeight,
     muscle_group: String,
     intensity: u8,
 }
 
 impl WorkoutInputs {
+    /// Duration of the training session, derived from its start/end time.
+    pub fn duration_minutes(&self) -> i64 {
+        (self.end_time - self.start_time).num_minutes()
+    }
+
     pub fn insert<C: Connection>(
         connection: &C,
         username: String,
-        date: String,
-        time: String,
-        body_weight: f32,
+        date: NaiveDate,
+        start_time: NaiveTime,
+        end_time: NaiveTime,
+        body_weight: BodyWeight,
         muscle_group: String,
         intensity: u8,
     ) -> Result<(), bonsaidb::core::Error> {
         WorkoutInputs {
             username,
             date,
-            time,
+            start_time,
+            end_time,
             body_weight,
             muscle_group,
             intensity,
@@ -174,11 +690,65 @@ impl WorkoutInputs {
 
 fn open_storage(path: &String) -> Result<Storage> {
     Ok(Storage::open(
-        StorageConfiguration::new(path).with_schema::<WorkoutInputs>()?,
+        StorageConfiguration::new(path)
+            .with_schema::<WorkoutInputs>()?
+            .with_schema::<ExerciseEntry>()?
+            .with_schema::<MuscleRating>()?,
     )?)
 }
 
+/// Where a command gets its database connections from. `WorkoutInputs::insert` and
+/// every view query are generic over `Connection`, so the same command code runs
+/// unchanged whether `acquire` hands back local storage or a networked client.
+///
+/// UNSHIPPABLE AS-IS: this checkout carries no `Cargo.toml` at all (not just missing
+/// `server`/`client` feature entries — there is no manifest anywhere in the tree, for any
+/// dependency, predating this change). `cfg(feature = "server"/"client")` below compiles to
+/// nothing without one, and `cargo build --features server` has no manifest to even resolve
+/// against. Landing networked mode for real needs a `Cargo.toml` adding `[features] server
+/// = ["dep:bonsaidb-server"]` / `client = ["dep:bonsaidb-client"]` (names depending on how
+/// `bonsaidb`'s sub-crates are pulled in) plus the matching optional deps — that manifest
+/// does not exist in this tree and isn't added by this commit.
+trait ConnectionSource {
+    type Db: Connection;
+
+    fn acquire<C: SerializedCollection>(&self, name: &str) -> Result<Self::Db>;
+}
+
+struct LocalSource {
+    path: String,
+}
+
+impl ConnectionSource for LocalSource {
+    type Db = bonsaidb::local::Database;
+
+    fn acquire<C: SerializedCollection>(&self, name: &str) -> Result<Self::Db> {
+        let storage = open_storage(&self.path)?;
+        Ok(storage.create_database::<C>(name, true)?)
+    }
+}
+
+#[cfg(feature = "client")]
+struct RemoteSource {
+    url: String,
+}
+
+#[cfg(feature = "client")]
+impl ConnectionSource for RemoteSource {
+    type Db = bonsaidb::client::BlockingRemoteDatabase;
+
+    fn acquire<C: SerializedCollection>(&self, name: &str) -> Result<Self::Db> {
+        let client = bonsaidb::client::BlockingClient::build(self.url.parse()?)
+            .with_schema::<WorkoutInputs>()?
+            .with_schema::<ExerciseEntry>()?
+            .with_schema::<MuscleRating>()?
+            .finish()?;
+        Ok(client.database::<C>(name)?)
+    }
+}
+
 fn insert_data(
+    source: &impl ConnectionSource,
     WriteInputsForCLI {
         username,
         date,
@@ -188,38 +758,142 @@ fn insert_data(
         intensity,
     }: WriteInputsForCLI,
 ) -> Result<()> {
-    let storage_connection =
-        open_storage(&DEFAULT_DB_PATH.to_string()).expect("Failed to create new database.");
-    let workout_connection = storage_connection
-        .create_database::<WorkoutInputs>("workout-data", true)
+    // Validate and parse up front so malformed input is rejected instead of silently stored.
+    let date = parse_date(&date)?;
+    let (start_time, end_time) = parse_time_range(&time)?;
+    let body_weight = BodyWeight::parse(&body_weight)?;
+    let duration_minutes = (end_time - start_time).num_minutes();
+    let rating_username = username.clone();
+    let rating_muscle_group = muscle_group.clone();
+
+    let workout_connection = source
+        .acquire::<WorkoutInputs>("workout-data")
         .expect("Failed to initalize storage connection.");
 
     WorkoutInputs::insert(
         &workout_connection,
         username,
         date,
-        time,
+        start_time,
+        end_time,
         body_weight,
         muscle_group,
         intensity,
     )
     .expect("Failed to insert into database. Check inputs.");
+    update_rating(source, &rating_username, &rating_muscle_group, intensity, duration_minutes)?;
     println!("Workout data has successfuly been inputed into the database.");
     Ok(())
 }
 
+/// How many attempts `update_rating` makes in total before giving up on conflicting concurrent writes.
+const MAX_RATING_UPDATE_ATTEMPTS: u32 = 5;
+
+/// Nudge the (username, muscle_group) rating with an Elo-style adjustment based on
+/// this session's normalized load, creating the pairing at [`INITIAL_RATING`] if new.
+fn update_rating(
+    source: &impl ConnectionSource,
+    username: &str,
+    muscle_group: &str,
+    intensity: u8,
+    duration_minutes: i64,
+) -> Result<()> {
+    let rating_db = source.acquire::<MuscleRating>("muscle-ratings")?;
+    let observed = normalized_load(intensity, duration_minutes);
+    let rating_id = rating_key(username, muscle_group);
+
+    // Keyed on (username, muscle_group), so a missing-then-insert race between two
+    // concurrent writers collapses into a document conflict on the loser, rather than
+    // two duplicate rows for the same pairing. Retry on conflict instead of giving up.
+    for _attempt in 0..MAX_RATING_UPDATE_ATTEMPTS {
+        match MuscleRating::get(&rating_id, &rating_db)? {
+            Some(mut rating_doc) => {
+                let k = decayed_k(rating_doc.contents.sessions_trained);
+                let expected = expected_from_rating(rating_doc.contents.rating);
+                rating_doc.contents.rating += k * (observed - expected);
+                rating_doc.contents.sessions_trained += 1;
+                rating_doc.contents.recent_loads.push(observed);
+                if rating_doc.contents.recent_loads.len() > RECENT_LOADS_WINDOW {
+                    rating_doc.contents.recent_loads.remove(0);
+                }
+                match rating_doc.update(&rating_db) {
+                    Ok(()) => return Ok(()),
+                    Err(bonsaidb::core::Error::DocumentConflict(..)) => continue,
+                    Err(error) => return Err(error.into()),
+                }
+            }
+            None => {
+                let k = decayed_k(0);
+                let expected = expected_from_rating(INITIAL_RATING);
+                let new_rating = MuscleRating {
+                    username: username.to_string(),
+                    muscle_group: muscle_group.to_string(),
+                    rating: INITIAL_RATING + k * (observed - expected),
+                    sessions_trained: 1,
+                    recent_loads: vec![observed],
+                };
+                match new_rating.insert_into(&rating_id, &rating_db) {
+                    Ok(_) => return Ok(()),
+                    Err(bonsaidb::core::Error::DocumentConflict(..)) => continue,
+                    Err(error) => return Err(error.into()),
+                }
+            }
+        }
+    }
+    bail!(
+        "failed to update rating for {username}/{muscle_group} after {MAX_RATING_UPDATE_ATTEMPTS} conflicting writes"
+    );
+}
+
+fn print_progress(source: &impl ConnectionSource, username: &str) -> Result<()> {
+    let rating_db = source.acquire::<MuscleRating>("muscle-ratings")?;
+    let ratings = rating_db
+        .view::<RatingView>()
+        .with_key(username)
+        .query_with_docs()?;
+
+    let mut rows = Vec::new();
+    for mapping in &ratings {
+        let contents = MuscleRating::document_contents(mapping.document)?;
+        let trending_down = is_trending_down(&contents.recent_loads);
+        rows.push((contents.muscle_group, contents.rating, trending_down));
+    }
+    rows.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+    if rows.is_empty() {
+        println!("No training history tracked for {username} yet.");
+        return Ok(());
+    }
+
+    for (muscle_group, rating, trending_down) in rows {
+        let trend = if trending_down { "declining" } else { "stable/improving" };
+        ptable!(
+            [
+                "Muscle group:",
+                muscle_group,
+                "Rating:",
+                format!("{rating:.0}"),
+                "Trend:",
+                trend
+            ]
+        );
+    }
+    Ok(())
+}
+
 extern crate prettytable;
 
-fn print_all_data(username: &str) -> Result<()> {
-    let storage_connection =
-        open_storage(&DEFAULT_DB_PATH.to_string()).expect("Failed to create new database.");
-    let workout_db = storage_connection.database::<WorkoutInputs>("workout-data")?;
+fn print_all_data(source: &impl ConnectionSource, username: &str) -> Result<()> {
+    let workout_db = source.acquire::<WorkoutInputs>("workout-data")?;
+    let exercise_db = source.acquire::<ExerciseEntry>("exercise-entries")?;
     let user_data = workout_db
         .view::<UserView>()
         .with_key(username)
         .query_with_docs()?;
     for mapping in &user_data {
         let data = WorkoutInputs::document_contents(mapping.document)?;
+        let session_id: u64 = mapping.document.header.id.into();
+        println!("Session id: {session_id}");
 
         ptable!(
             [
@@ -228,7 +902,12 @@ fn print_all_data(username: &str) -> Result<()> {
                 "Date:",
                 data.date
             ],
-            ["Time at gym:", data.time, "Body weight:", data.body_weight],
+            [
+                "Time at gym:",
+                format!("{}-{}", data.start_time, data.end_time),
+                "Body weight:",
+                data.body_weight
+            ],
             [
                 "Muscle group trained:",
                 data.muscle_group,
@@ -236,19 +915,19 @@ fn print_all_data(username: &str) -> Result<()> {
                 data.intensity
             ]
         );
+        print_exercises_for_session(&exercise_db, session_id)?;
     }
     Ok(())
 }
 
 // Need to match user
-fn print_specific_day(username: &str, date: &str) -> Result<()> {
-    let storage_connection =
-        open_storage(&DEFAULT_DB_PATH.to_string()).expect("Failed to create new database.");
-    let workout_db = storage_connection.database::<WorkoutInputs>("workout-data")?;
+fn print_specific_day(source: &impl ConnectionSource, username: &str, date: &str) -> Result<()> {
+    let date = parse_date(date)?;
+    let workout_db = source.acquire::<WorkoutInputs>("workout-data")?;
 
     let date_specific_data = workout_db
         .view::<DateView>()
-        .with_key(date)
+        .with_key(date.num_days_from_ce())
         .query_with_docs()?;
     for mapping in &date_specific_data {
         let data = WorkoutInputs::document_contents(mapping.document)?;
@@ -260,7 +939,12 @@ fn print_specific_day(username: &str, date: &str) -> Result<()> {
                     "Date:",
                     data.date
                 ],
-                ["Time at gym:", data.time, "Body weight:", data.body_weight],
+                [
+                    "Time at gym:",
+                    format!("{}-{}", data.start_time, data.end_time),
+                    "Body weight:",
+                    data.body_weight
+                ],
                 [
                     "Muscle group trained:",
                     data.muscle_group,
@@ -273,12 +957,202 @@ fn print_specific_day(username: &str, date: &str) -> Result<()> {
     Ok(())
 }
 
-use crate::MethodType::{ReadDate, ReadLogs, Write};
+/// TF-IDF contribution of one term match: raw term frequency weighted by how rare the term
+/// is across the target user's own sessions. A term present in every session contributes 0.
+fn tfidf_score(term_frequency: f64, docs_containing_term: f64, total_docs: f64) -> f64 {
+    term_frequency * (total_docs / docs_containing_term).ln()
+}
 
-fn run(args: GymtrackerArgs) {
-    match args.user_method {
-        ReadLogs { username } => print_all_data(&username),
-        ReadDate { username, date } => print_specific_day(&username, &date),
+fn search_workouts(source: &impl ConnectionSource, username: &str, query: &str) -> Result<()> {
+    let workout_db = source.acquire::<WorkoutInputs>("workout-data")?;
+
+    // Scoped to this user: one mapping per session they've logged.
+    let total_docs = workout_db.view::<UserView>().with_key(username).query()?.len() as f64;
+
+    let terms: Vec<String> = tokenize(query).into_iter().collect::<HashSet<_>>().into_iter().collect();
+    let mut scores: HashMap<u64, f64> = HashMap::new();
+    for term in &terms {
+        let matches = workout_db
+            .view::<SearchView>()
+            .with_key(&(username.to_string(), term.clone()))
+            .query()?;
+        let docs_containing_term = matches.len() as f64;
+        if docs_containing_term == 0.0 {
+            continue;
+        }
+        for mapping in &matches {
+            let term_frequency = mapping.value as f64;
+            *scores.entry(mapping.source.id.into()).or_insert(0.0) +=
+                tfidf_score(term_frequency, docs_containing_term, total_docs);
+        }
+    }
+
+    let mut ranked: Vec<(u64, f64)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+    for (document_id, score) in ranked {
+        let document = WorkoutInputs::get(&document_id, &workout_db)?;
+        if let Some(document) = document {
+            let data = document.contents;
+            ptable!(
+                [
+                    "Matched workout for:",
+                    data.username,
+                    "Date:",
+                    data.date
+                ],
+                ["Muscle group trained:", data.muscle_group, "Score:", score]
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_lowercases_and_splits_on_punctuation() {
+        assert_eq!(tokenize("Legs, Back & Biceps!"), vec!["legs", "back", "biceps"]);
+    }
+
+    #[test]
+    fn tokenize_drops_empty_terms() {
+        assert_eq!(tokenize("  --Chest--  "), vec!["chest"]);
+    }
+
+    #[test]
+    fn tokenize_empty_input_has_no_terms() {
+        assert!(tokenize("   ").is_empty());
+    }
+
+    #[test]
+    fn tfidf_score_rewards_rarer_terms() {
+        let rare = tfidf_score(1.0, 1.0, 10.0);
+        let common = tfidf_score(1.0, 9.0, 10.0);
+        assert!(rare > common);
+    }
+
+    #[test]
+    fn tfidf_score_is_zero_when_term_is_in_every_doc() {
+        assert_eq!(tfidf_score(1.0, 10.0, 10.0), 0.0);
+    }
+
+    #[test]
+    fn exact_multi_term_match_outranks_partial_match() {
+        // "legs" appears in 4 of 10 sessions, "day" in 8 of 10.
+        let legs_idf = tfidf_score(1.0, 4.0, 10.0);
+        let day_idf = tfidf_score(1.0, 8.0, 10.0);
+
+        let exact_match_score = legs_idf + day_idf; // doc matches both query terms
+        let partial_match_score = day_idf; // doc matches only the common term
+
+        assert!(exact_match_score > partial_match_score);
+    }
+}
+
+fn print_summary(source: &impl ConnectionSource, username: &str) -> Result<()> {
+    let workout_db = source.acquire::<WorkoutInputs>("workout-data")?;
+
+    let stats = workout_db.view::<StatsView>().with_key(username).query_reduce()?;
+
+    if stats.session_count == 0 {
+        println!("No workouts tracked for {username} yet.");
+        return Ok(());
+    }
+
+    let average_intensity = stats.intensity_sum as f64 / stats.session_count as f64;
+    let average_body_weight = stats.body_weight_sum as f64 / stats.session_count as f64;
+
+    ptable!(
+        ["Summary for:", username],
+        ["Sessions tracked:", stats.session_count],
+        ["Total time trained (minutes):", stats.total_duration_minutes],
+        ["Average intensity:", format!("{average_intensity:.1}")],
+        ["Average body weight (lbs):", format!("{average_body_weight:.1}")]
+    );
+    Ok(())
+}
+
+fn add_exercise(
+    source: &impl ConnectionSource,
+    session_id: u64,
+    exercise_name: String,
+    attribute: String,
+    raw_value: String,
+) -> Result<()> {
+    let value = serde_json::from_str(&raw_value).unwrap_or(JsonValue::String(raw_value));
+
+    let workout_db = source.acquire::<WorkoutInputs>("workout-data")?;
+    if WorkoutInputs::get(&session_id, &workout_db)?.is_none() {
+        bail!("no session with id {session_id} exists; check ReadLogs for valid session ids");
+    }
+
+    let exercise_connection = source
+        .acquire::<ExerciseEntry>("exercise-entries")
+        .expect("Failed to initalize storage connection.");
+
+    ExerciseEntry::insert(&exercise_connection, session_id, exercise_name, attribute, value)
+        .expect("Failed to insert into database. Check inputs.");
+    println!("Exercise entry has successfuly been inputed into the database.");
+    Ok(())
+}
+
+fn print_exercises_for_session(exercise_db: &impl Connection, session_id: u64) -> Result<()> {
+    let entries = exercise_db
+        .view::<ExerciseEntryView>()
+        .with_key(session_id)
+        .query()?;
+    for mapping in &entries {
+        let (exercise_name, attribute, value) = &mapping.value;
+        ptable!(["  Exercise:", exercise_name, attribute, value]);
+    }
+    Ok(())
+}
+
+fn read_exercises(source: &impl ConnectionSource, session_id: u64) -> Result<()> {
+    let exercise_db = source.acquire::<ExerciseEntry>("exercise-entries")?;
+    print_exercises_for_session(&exercise_db, session_id)
+}
+
+#[cfg(feature = "server")]
+fn serve(bind: String) -> Result<()> {
+    use bonsaidb::server::{Server, ServerConfiguration};
+
+    let server = Server::open(
+        ServerConfiguration::new(DEFAULT_DB_PATH)
+            .with_schema::<WorkoutInputs>()?
+            .with_schema::<ExerciseEntry>()?
+            .with_schema::<MuscleRating>()?,
+    )?;
+    server.create_database::<WorkoutInputs>("workout-data", true)?;
+    server.create_database::<ExerciseEntry>("exercise-entries", true)?;
+    server.create_database::<MuscleRating>("muscle-ratings", true)?;
+
+    println!("Serving gymtracker database on {bind}...");
+    server.listen_on(bind.parse()?)?;
+    Ok(())
+}
+
+use crate::MethodType::{
+    AddExercise, Progress, ReadDate, ReadExercises, ReadLogs, Search, Summary, Write,
+};
+
+fn dispatch(method: MethodType, source: &impl ConnectionSource) -> Result<()> {
+    match method {
+        ReadLogs { username } => print_all_data(source, &username),
+        ReadDate { username, date } => print_specific_day(source, &username, &date),
+        Search { username, query } => search_workouts(source, &username, &query),
+        Summary { username } => print_summary(source, &username),
+        AddExercise {
+            session_id,
+            exercise_name,
+            attribute,
+            value,
+        } => add_exercise(source, session_id, exercise_name, attribute, value),
+        ReadExercises { session_id } => read_exercises(source, session_id),
+        Progress { username } => print_progress(source, &username),
         Write {
             username,
             date,
@@ -286,15 +1160,41 @@ fn run(args: GymtrackerArgs) {
             body_weight,
             muscle_group,
             intensity,
-        } => insert_data(WriteInputsForCLI {
-            username,
-            date,
-            time,
-            body_weight,
-            muscle_group,
-            intensity,
-        }),
+        } => insert_data(
+            source,
+            WriteInputsForCLI {
+                username,
+                date,
+                time,
+                body_weight,
+                muscle_group,
+                intensity,
+            },
+        ),
+        #[cfg(feature = "server")]
+        MethodType::Serve { .. } => unreachable!("Serve is handled in run() before dispatch"),
     }
+}
+
+fn run(args: GymtrackerArgs) {
+    #[cfg(feature = "server")]
+    if let MethodType::Serve { bind } = args.user_method {
+        serve(bind).unwrap();
+        return;
+    }
+
+    #[cfg(feature = "client")]
+    if let Some(url) = args.remote {
+        dispatch(args.user_method, &RemoteSource { url }).unwrap();
+        return;
+    }
+
+    dispatch(
+        args.user_method,
+        &LocalSource {
+            path: DEFAULT_DB_PATH.to_string(),
+        },
+    )
     .unwrap();
 }
 